@@ -0,0 +1,127 @@
+//! Pluggable "craft me a search query" backends, selected via `--ai-backend`.
+
+mod ollama;
+mod openai;
+mod vertexai;
+
+use crate::forge::Forge;
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use clap::ValueEnum;
+use serde::Deserialize;
+
+pub use ollama::OllamaSuggester;
+pub use openai::OpenAiSuggester;
+pub use vertexai::VertexAiSuggester;
+
+/// Which backend to ask for a crafted search query, selected via
+/// `--ai-backend`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum AiBackend {
+    Openai,
+    Ollama,
+    Vertexai,
+}
+
+/// Crafts a forge search query from a free-text description.
+#[async_trait]
+pub trait QuerySuggester {
+    async fn suggest(&self, description: &str, forge: Forge) -> Result<String>;
+}
+
+/// Build the suggester selected by `backend`, with an optional `--ai-model`
+/// override (each backend otherwise picks its own sensible default).
+pub fn build_suggester(backend: AiBackend, model: Option<&str>) -> Result<Box<dyn QuerySuggester>> {
+    match backend {
+        AiBackend::Openai => Ok(Box::new(OpenAiSuggester::new(model))),
+        AiBackend::Ollama => Ok(Box::new(OllamaSuggester::new(model))),
+        AiBackend::Vertexai => Ok(Box::new(VertexAiSuggester::new(model)?)),
+    }
+}
+
+#[derive(Deserialize)]
+struct Suggestion {
+    query: String,
+}
+
+/// Shared across all backends: models tend to wrap their JSON answer in
+/// ```json fences despite being asked not to, so defensively strip those
+/// before parsing `{"query": "..."}`.
+pub(crate) fn parse_query_from_content(content: &str) -> Result<String> {
+    if let Ok(s) = serde_json::from_str::<Suggestion>(content) {
+        return Ok(s.query);
+    }
+
+    let trimmed = content.trim();
+    if let Some(stripped) = strip_code_fence(trimmed) {
+        if let Ok(s) = serde_json::from_str::<Suggestion>(&stripped) {
+            return Ok(s.query);
+        }
+    }
+
+    Err(anyhow!(
+        "AI backend response was not valid JSON (first 200 chars): {}",
+        truncate_preview(content, 200)
+    ))
+}
+
+fn strip_code_fence(input: &str) -> Option<String> {
+    if !input.starts_with("```") {
+        return None;
+    }
+
+    let mut lines = input.lines();
+    // Drop opening fence (maybe with language tag).
+    lines.next()?;
+
+    let mut body_lines = Vec::new();
+    for line in lines {
+        if line.trim() == "```" {
+            break;
+        }
+        body_lines.push(line);
+    }
+
+    if body_lines.is_empty() {
+        return None;
+    }
+
+    Some(body_lines.join("\n").trim().to_string())
+}
+
+fn truncate_preview(content: &str, max_chars: usize) -> String {
+    let mut preview = content.chars().take(max_chars).collect::<String>();
+    if content.chars().count() > max_chars {
+        preview.push_str("…");
+    }
+    preview
+}
+
+/// Shared across backends: the instruction each one sends alongside the
+/// user's free-text description, naming whichever forge the search will
+/// actually run against so the model doesn't default to GitHub syntax.
+pub(crate) fn suggestion_prompt(description: &str, forge: Forge) -> String {
+    format!(
+        "You are helping craft a concise {forge:?} search query to find repositories with releases. \
+Description: \"{description}\". Respond as JSON: {{\"query\": \"...\"}} with no extra text."
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_query_from_content;
+
+    #[test]
+    fn parses_plain_json() {
+        let content = r#"{"query":"foo bar"}"#;
+        let q = parse_query_from_content(content).expect("should parse plain json");
+        assert_eq!(q, "foo bar");
+    }
+
+    #[test]
+    fn parses_code_fenced_json() {
+        let content = "```json\n{\"query\":\"ripgrep\"}\n```";
+        let q = parse_query_from_content(content).expect("should parse fenced json");
+        assert_eq!(q, "ripgrep");
+    }
+}
@@ -0,0 +1,82 @@
+use super::{QuerySuggester, parse_query_from_content, suggestion_prompt};
+use crate::forge::Forge;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::env;
+
+const DEFAULT_HOST: &str = "http://localhost:11434";
+const DEFAULT_MODEL: &str = "llama3";
+
+/// Talks to a local `ollama serve` instance, so users without an OpenAI key
+/// can still get AI-assisted query crafting.
+pub struct OllamaSuggester {
+    client: reqwest::Client,
+    host: String,
+    model: String,
+}
+
+impl OllamaSuggester {
+    pub fn new(model: Option<&str>) -> Self {
+        let host = env::var("OLLAMA_HOST").unwrap_or_else(|_| DEFAULT_HOST.to_string());
+        let model = model
+            .map(str::to_string)
+            .or_else(|| env::var("OLLAMA_MODEL").ok())
+            .unwrap_or_else(|| DEFAULT_MODEL.to_string());
+        Self {
+            client: reqwest::Client::new(),
+            host: host.trim_end_matches('/').to_string(),
+            model,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: [ChatMessage; 1],
+    stream: bool,
+}
+
+#[derive(Serialize)]
+struct ChatMessage {
+    role: &'static str,
+    content: String,
+}
+
+#[derive(Deserialize)]
+struct ChatResponse {
+    message: ChatResponseMessage,
+}
+
+#[derive(Deserialize)]
+struct ChatResponseMessage {
+    content: String,
+}
+
+#[async_trait]
+impl QuerySuggester for OllamaSuggester {
+    async fn suggest(&self, description: &str, forge: Forge) -> Result<String> {
+        let req = ChatRequest {
+            model: &self.model,
+            messages: [ChatMessage {
+                role: "user",
+                content: suggestion_prompt(description, forge),
+            }],
+            stream: false,
+        };
+
+        let resp = self
+            .client
+            .post(format!("{}/api/chat", self.host))
+            .json(&req)
+            .send()
+            .await
+            .context("failed to reach Ollama (is `ollama serve` running?)")?
+            .error_for_status()
+            .context("Ollama chat request failed")?;
+
+        let body: ChatResponse = resp.json().await?;
+        parse_query_from_content(&body.message.content)
+    }
+}
@@ -0,0 +1,54 @@
+use super::{QuerySuggester, parse_query_from_content, suggestion_prompt};
+use crate::forge::Forge;
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+
+const DEFAULT_MODEL: &str = "gpt-4o-mini";
+
+pub struct OpenAiSuggester {
+    model: String,
+}
+
+impl OpenAiSuggester {
+    pub fn new(model: Option<&str>) -> Self {
+        Self {
+            model: model.unwrap_or(DEFAULT_MODEL).to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl QuerySuggester for OpenAiSuggester {
+    async fn suggest(&self, description: &str, forge: Forge) -> Result<String> {
+        use async_openai::{
+            Client,
+            types::{
+                ChatCompletionRequestMessage, ChatCompletionRequestUserMessage,
+                ChatCompletionRequestUserMessageContent, CreateChatCompletionRequestArgs,
+                ResponseFormat,
+            },
+        };
+
+        let client = Client::new();
+        let user_msg = ChatCompletionRequestMessage::User(ChatCompletionRequestUserMessage {
+            content: ChatCompletionRequestUserMessageContent::Text(suggestion_prompt(description, forge)),
+            name: None,
+        });
+
+        let req = CreateChatCompletionRequestArgs::default()
+            .model(&self.model)
+            .messages([user_msg])
+            // Ask the API to emit strict JSON; still defensively parse below.
+            .response_format(ResponseFormat::JsonObject)
+            .build()?;
+
+        let resp = client.chat().create(req).await?;
+        let content = resp
+            .choices
+            .first()
+            .and_then(|c| c.message.content.as_ref())
+            .ok_or_else(|| anyhow!("OpenAI returned empty content"))?;
+
+        parse_query_from_content(content)
+    }
+}
@@ -0,0 +1,73 @@
+use super::{QuerySuggester, parse_query_from_content, suggestion_prompt};
+use crate::forge::Forge;
+use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
+use std::env;
+
+const DEFAULT_LOCATION: &str = "us-central1";
+const DEFAULT_MODEL: &str = "gemini-1.5-flash";
+
+pub struct VertexAiSuggester {
+    client: reqwest::Client,
+    project: String,
+    location: String,
+    model: String,
+    access_token: String,
+}
+
+impl VertexAiSuggester {
+    pub fn new(model: Option<&str>) -> Result<Self> {
+        let project = env::var("VERTEXAI_PROJECT")
+            .context("--ai-backend vertexai requires the VERTEXAI_PROJECT env var")?;
+        let location = env::var("VERTEXAI_LOCATION").unwrap_or_else(|_| DEFAULT_LOCATION.to_string());
+        let access_token = env::var("VERTEXAI_ACCESS_TOKEN").context(
+            "--ai-backend vertexai requires the VERTEXAI_ACCESS_TOKEN env var \
+             (e.g. the output of `gcloud auth print-access-token`)",
+        )?;
+        let model = model
+            .map(str::to_string)
+            .or_else(|| env::var("VERTEXAI_MODEL").ok())
+            .unwrap_or_else(|| DEFAULT_MODEL.to_string());
+
+        Ok(Self {
+            client: reqwest::Client::new(),
+            project,
+            location,
+            model,
+            access_token,
+        })
+    }
+}
+
+#[async_trait]
+impl QuerySuggester for VertexAiSuggester {
+    async fn suggest(&self, description: &str, forge: Forge) -> Result<String> {
+        let url = format!(
+            "https://{location}-aiplatform.googleapis.com/v1/projects/{project}/locations/{location}/publishers/google/models/{model}:generateContent",
+            location = self.location,
+            project = self.project,
+            model = self.model,
+        );
+        let body = serde_json::json!({
+            "contents": [{"role": "user", "parts": [{"text": suggestion_prompt(description, forge)}]}]
+        });
+
+        let resp = self
+            .client
+            .post(url)
+            .bearer_auth(&self.access_token)
+            .json(&body)
+            .send()
+            .await
+            .context("failed to reach Vertex AI")?
+            .error_for_status()
+            .context("Vertex AI generateContent request failed")?;
+
+        let value: serde_json::Value = resp.json().await?;
+        let content = value["candidates"][0]["content"]["parts"][0]["text"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Vertex AI response had no candidate text"))?;
+
+        parse_query_from_content(content)
+    }
+}
@@ -0,0 +1,120 @@
+//! On-disk TTL cache for raw HTTP response bodies, keyed by request URL.
+//!
+//! Search results and release lookups rarely change within a session, and
+//! GitHub/GitLab/Gitea all rate-limit unauthenticated or bursty callers.
+//! Caching the raw body under `dirs::cache_dir()/grit-find/` lets repeated
+//! or paged queries reuse a recent response instead of re-hitting the API.
+//! Entries also keep the response's ETag (if any), so once an entry goes
+//! stale, callers can revalidate with `If-None-Match` and just refresh the
+//! entry's age on a `304` instead of paying for a full response body again.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs,
+    hash::{Hash, Hasher},
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[derive(Clone)]
+pub struct Cache {
+    dir: Option<PathBuf>,
+    ttl: Duration,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    fetched_at: u64,
+    body: String,
+    #[serde(default)]
+    etag: Option<String>,
+}
+
+impl Cache {
+    /// A cache rooted at `dirs::cache_dir()/grit-find`, or a no-op cache if
+    /// `disabled` (`--no-cache`) or the platform has no cache dir.
+    pub fn new(ttl: Duration, disabled: bool) -> Self {
+        let dir = if disabled {
+            None
+        } else {
+            dirs::cache_dir().map(|d| d.join("grit-find"))
+        };
+        Self { dir, ttl }
+    }
+
+    /// Return the cached body for `url` if present and not yet expired.
+    pub fn get(&self, url: &str) -> Option<String> {
+        let entry = self.read_entry(url)?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+        if now.saturating_sub(entry.fetched_at) > self.ttl.as_secs() {
+            return None;
+        }
+        Some(entry.body)
+    }
+
+    /// Return the ETag stored for `url`, if any, even if the entry has
+    /// expired — used to send `If-None-Match` when revalidating a stale
+    /// entry instead of refetching it unconditionally.
+    pub fn etag(&self, url: &str) -> Option<String> {
+        self.read_entry(url)?.etag
+    }
+
+    /// Return the body stored for `url` regardless of freshness — used once
+    /// a `304 Not Modified` response has confirmed a stale entry is still
+    /// current.
+    pub fn get_stale(&self, url: &str) -> Option<String> {
+        self.read_entry(url).map(|e| e.body)
+    }
+
+    /// Store `body` (and optional `etag`) for `url`, overwriting any
+    /// existing entry.
+    pub fn put(&self, url: &str, body: &str, etag: Option<&str>) -> Result<()> {
+        let Some(path) = self.path_for(url) else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let entry = Entry {
+            fetched_at: now_secs(),
+            body: body.to_string(),
+            etag: etag.map(str::to_string),
+        };
+        fs::write(path, serde_json::to_vec(&entry)?)?;
+        Ok(())
+    }
+
+    /// Refresh `url`'s `fetched_at` to now, keeping its stored body/etag —
+    /// called after a `304 Not Modified` revalidation confirms the entry is
+    /// still current.
+    pub fn touch(&self, url: &str) -> Result<()> {
+        let Some(mut entry) = self.read_entry(url) else {
+            return Ok(());
+        };
+        entry.fetched_at = now_secs();
+        let Some(path) = self.path_for(url) else {
+            return Ok(());
+        };
+        fs::write(path, serde_json::to_vec(&entry)?)?;
+        Ok(())
+    }
+
+    fn read_entry(&self, url: &str) -> Option<Entry> {
+        serde_json::from_slice(&fs::read(self.path_for(url)?).ok()?).ok()
+    }
+
+    fn path_for(&self, url: &str) -> Option<PathBuf> {
+        let mut hasher = DefaultHasher::new();
+        url.hash(&mut hasher);
+        Some(self.dir.as_ref()?.join(format!("{:016x}.json", hasher.finish())))
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
@@ -0,0 +1,89 @@
+//! SHA-256 verification of downloaded assets against a sibling checksum
+//! manifest (`checksums.txt`, `SHA256SUMS`, ...) shipped in the same release.
+
+use crate::forge::Asset;
+use std::collections::HashMap;
+
+const MANIFEST_NAMES: &[&str] = &[
+    "checksums.txt",
+    "checksums.sha256",
+    "sha256sums",
+    "sha256sums.txt",
+    "sha256sum.txt",
+];
+
+/// Find the asset in `assets` that looks like a checksum manifest for the
+/// others, if any.
+pub fn find_manifest<'a>(assets: &'a [Asset]) -> Option<&'a Asset> {
+    assets
+        .iter()
+        .find(|a| MANIFEST_NAMES.contains(&a.name.to_lowercase().as_str()))
+}
+
+/// Parse `<hex digest>  <filename>` lines (the `sha256sum`/GitHub Actions
+/// convention) into a filename -> lowercase hex digest map.
+pub fn parse_manifest(text: &str) -> HashMap<String, String> {
+    let mut digests = HashMap::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let mut parts = line.splitn(2, char::is_whitespace);
+        let Some(hash) = parts.next() else { continue };
+        let Some(filename) = parts.next() else {
+            continue;
+        };
+        // sha256sum prefixes the filename with a mode char ('*' for binary).
+        let filename = filename.trim_start_matches(['*', ' ']).trim();
+        if hash.len() == 64 && hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+            digests.insert(filename.to_string(), hash.to_lowercase());
+        }
+    }
+    digests
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sha256sum_style_manifest() {
+        let text = "\
+deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef  tool-linux-x86_64.tar.gz
+cafebabecafebabecafebabecafebabecafebabecafebabecafebabecafebabe *tool-darwin-arm64.tar.gz
+";
+        let digests = parse_manifest(text);
+        assert_eq!(
+            digests.get("tool-linux-x86_64.tar.gz").map(String::as_str),
+            Some("deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef")
+        );
+        assert_eq!(
+            digests.get("tool-darwin-arm64.tar.gz").map(String::as_str),
+            Some("cafebabecafebabecafebabecafebabecafebabecafebabecafebabecafebabe")
+        );
+    }
+
+    #[test]
+    fn ignores_blank_lines_and_short_hashes() {
+        let text = "\n   \nnothexlikeadigest tool.tar.gz\n";
+        assert!(parse_manifest(text).is_empty());
+    }
+
+    #[test]
+    fn find_manifest_matches_known_names() {
+        let assets = vec![
+            Asset {
+                name: "tool-linux.tar.gz".into(),
+                download_url: "https://example.com/tool-linux.tar.gz".into(),
+                size: 10,
+            },
+            Asset {
+                name: "checksums.txt".into(),
+                download_url: "https://example.com/checksums.txt".into(),
+                size: 1,
+            },
+        ];
+        assert_eq!(find_manifest(&assets).unwrap().name, "checksums.txt");
+    }
+}
@@ -0,0 +1,163 @@
+use super::{Asset, ForgeProvider, Release, Repo, require_host};
+use crate::cache::Cache;
+use crate::http::HttpClient;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::{
+    StatusCode, Url,
+    header::{AUTHORIZATION, HeaderMap, HeaderValue, USER_AGENT},
+};
+use serde::Deserialize;
+use std::{env, time::Duration};
+
+pub struct GiteaProvider {
+    client: reqwest::Client,
+    http: HttpClient,
+    api_base: String,
+    web_base: String,
+    cache: Cache,
+}
+
+impl GiteaProvider {
+    pub fn new(host: Option<&str>, cache: Cache) -> Result<Self> {
+        let host = require_host("gitea", host)?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static("grit-find"));
+
+        if let Ok(token) = env::var("GITEA_TOKEN") {
+            let mut value = HeaderValue::from_str(&format!("token {token}"))?;
+            value.set_sensitive(true);
+            headers.insert(AUTHORIZATION, value);
+        }
+
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(Duration::from_secs(20))
+            .build()
+            .context("failed to build Gitea HTTP client")?;
+
+        let host = host.trim_end_matches('/').to_string();
+        Ok(Self {
+            http: HttpClient::new(client.clone()),
+            client,
+            api_base: format!("{host}/api/v1"),
+            web_base: host,
+            cache,
+        })
+    }
+
+    /// GET `url`, transparently serving a fresh cache entry if present. On a
+    /// stale or missing entry, revalidates with `If-None-Match` when we have
+    /// a stored ETag: a `304` just refreshes the entry's age and reuses the
+    /// cached body, while a `200` overwrites it.
+    async fn get_cached(&self, url: Url) -> Result<String> {
+        if let Some(body) = self.cache.get(url.as_str()) {
+            return Ok(body);
+        }
+        let etag = self.cache.etag(url.as_str());
+
+        let res = self.http.get_conditional(url.clone(), etag.as_deref()).await?;
+        if res.status == StatusCode::NOT_MODIFIED {
+            if let Some(body) = self.cache.get_stale(url.as_str()) {
+                self.cache.touch(url.as_str())?;
+                return Ok(body);
+            }
+        }
+
+        let res = res.error_for_status()?;
+        let etag = res.header("etag").map(str::to_string);
+        self.cache.put(url.as_str(), &res.body, etag.as_deref())?;
+        Ok(res.body)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaSearchResponse {
+    data: Vec<GiteaRepo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaRepo {
+    full_name: String,
+    description: Option<String>,
+    stars_count: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaRelease {
+    tag_name: String,
+    name: Option<String>,
+    assets: Vec<GiteaAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaAsset {
+    name: String,
+    browser_download_url: String,
+    size: u64,
+}
+
+#[async_trait]
+impl ForgeProvider for GiteaProvider {
+    async fn search_repos(&self, query: &str, per_page: usize, page: usize) -> Result<Vec<Repo>> {
+        let url = Url::parse_with_params(
+            &format!("{}/repos/search", self.api_base),
+            [
+                ("q", query),
+                ("limit", &per_page.to_string()),
+                ("page", &page.to_string()),
+                ("sort", "stars"),
+                ("order", "desc"),
+            ],
+        )?;
+
+        let body = self
+            .get_cached(url)
+            .await
+            .context("Gitea repo search failed")?;
+        let search: GiteaSearchResponse = serde_json::from_str(&body)?;
+        Ok(search
+            .data
+            .into_iter()
+            .map(|r| Repo {
+                full_name: r.full_name,
+                description: r.description,
+                stargazers_count: r.stars_count,
+            })
+            .collect())
+    }
+
+    async fn latest_release(&self, full_name: &str) -> Result<Release> {
+        let url = Url::parse(&format!(
+            "{}/repos/{full_name}/releases/latest",
+            self.api_base
+        ))?;
+        let body = self
+            .get_cached(url)
+            .await
+            .with_context(|| format!("Failed to fetch latest release for {full_name}"))?;
+        let release: GiteaRelease = serde_json::from_str(&body)?;
+        Ok(Release {
+            tag_name: release.tag_name,
+            name: release.name,
+            assets: release
+                .assets
+                .into_iter()
+                .map(|a| Asset {
+                    name: a.name,
+                    download_url: a.browser_download_url,
+                    size: a.size,
+                })
+                .collect(),
+        })
+    }
+
+    fn clone_url(&self, full_name: &str) -> String {
+        format!("{}/{full_name}.git", self.web_base)
+    }
+
+    fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+}
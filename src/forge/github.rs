@@ -0,0 +1,177 @@
+use super::{Asset, ForgeProvider, Release, Repo};
+use crate::cache::Cache;
+use crate::http::HttpClient;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::{
+    StatusCode, Url,
+    header::{ACCEPT, AUTHORIZATION, HeaderMap, HeaderValue, USER_AGENT},
+};
+use serde::Deserialize;
+use std::{env, time::Duration};
+use tokio::time::sleep;
+
+const DEFAULT_HOST: &str = "https://api.github.com";
+const DEFAULT_WEB_HOST: &str = "https://github.com";
+
+pub struct GitHubProvider {
+    client: reqwest::Client,
+    http: HttpClient,
+    api_base: String,
+    web_base: String,
+    cache: Cache,
+}
+
+impl GitHubProvider {
+    pub fn new(host: Option<&str>, cache: Cache) -> Result<Self> {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_str("grit-find (github.com)")?);
+        headers.insert(
+            ACCEPT,
+            HeaderValue::from_static("application/vnd.github+json"),
+        );
+
+        if let Ok(token) = env::var("GITHUB_TOKEN") {
+            let mut value = HeaderValue::from_str(&format!("Bearer {}", token))?;
+            value.set_sensitive(true);
+            headers.insert(AUTHORIZATION, value);
+        }
+
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .user_agent("grit-find")
+            .timeout(Duration::from_secs(20))
+            .build()
+            .context("failed to build GitHub HTTP client")?;
+
+        // GitHub Enterprise Server serves the REST API under `/api/v3` on
+        // the customer's own host; github.com's API lives on a separate
+        // `api.github.com` host with no such prefix.
+        let (api_base, web_base) = match host {
+            Some(host) => {
+                let host = host.trim_end_matches('/').to_string();
+                (format!("{host}/api/v3"), host)
+            }
+            None => (DEFAULT_HOST.to_string(), DEFAULT_WEB_HOST.to_string()),
+        };
+        Ok(Self {
+            http: HttpClient::new(client.clone()),
+            client,
+            api_base,
+            web_base,
+            cache,
+        })
+    }
+
+    /// GET `url`, transparently serving a fresh cache entry if present. On a
+    /// stale or missing entry, revalidates with `If-None-Match` when we have
+    /// a stored ETag: a `304` just refreshes the entry's age and reuses the
+    /// cached body, while a `200` overwrites it. On a rate-limit response,
+    /// retries up to twice (three attempts in all), waiting as long as the
+    /// `retry-after` header says (5 seconds if it is absent).
+    async fn get_cached(&self, url: Url) -> Result<String> {
+        if let Some(body) = self.cache.get(url.as_str()) {
+            return Ok(body);
+        }
+        let etag = self.cache.etag(url.as_str());
+
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let res = self.http.get_conditional(url.clone(), etag.as_deref()).await?;
+            if res.status == StatusCode::TOO_MANY_REQUESTS && attempts < 3 {
+                let wait = res
+                    .header("retry-after")
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .unwrap_or(5);
+                println!("Hit GitHub rate limit. Waiting {wait} seconds...");
+                if !self.http.is_replaying() {
+                    sleep(Duration::from_secs(wait)).await;
+                }
+                continue;
+            }
+
+            if res.status == StatusCode::NOT_MODIFIED {
+                if let Some(body) = self.cache.get_stale(url.as_str()) {
+                    self.cache.touch(url.as_str())?;
+                    return Ok(body);
+                }
+            }
+
+            let res = res.error_for_status()?;
+            let etag = res.header("etag").map(str::to_string);
+            self.cache.put(url.as_str(), &res.body, etag.as_deref())?;
+            return Ok(res.body);
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    items: Vec<Repo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRelease {
+    tag_name: String,
+    name: Option<String>,
+    assets: Vec<GitHubAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubAsset {
+    name: String,
+    browser_download_url: String,
+    size: u64,
+}
+
+#[async_trait]
+impl ForgeProvider for GitHubProvider {
+    async fn search_repos(&self, query: &str, per_page: usize, page: usize) -> Result<Vec<Repo>> {
+        let q = format!("{query} is:public");
+        let url = Url::parse_with_params(
+            &format!("{}/search/repositories", self.api_base),
+            [
+                ("q", q.as_str()),
+                ("per_page", &per_page.to_string()),
+                ("page", &page.to_string()),
+                ("sort", "stars"),
+                ("order", "desc"),
+            ],
+        )?;
+
+        let body = self.get_cached(url).await.context("GitHub search failed")?;
+        let search: SearchResponse = serde_json::from_str(&body)?;
+        Ok(search.items)
+    }
+
+    async fn latest_release(&self, full_name: &str) -> Result<Release> {
+        let url = Url::parse(&format!("{}/repos/{full_name}/releases/latest", self.api_base))?;
+        let body = self
+            .get_cached(url)
+            .await
+            .with_context(|| format!("Failed to fetch latest release for {full_name}"))?;
+        let release: GitHubRelease = serde_json::from_str(&body)?;
+        Ok(Release {
+            tag_name: release.tag_name,
+            name: release.name,
+            assets: release
+                .assets
+                .into_iter()
+                .map(|a| Asset {
+                    name: a.name,
+                    download_url: a.browser_download_url,
+                    size: a.size,
+                })
+                .collect(),
+        })
+    }
+
+    fn clone_url(&self, full_name: &str) -> String {
+        format!("{}/{full_name}.git", self.web_base)
+    }
+
+    fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+}
@@ -0,0 +1,168 @@
+use super::{Asset, ForgeProvider, Release, Repo};
+use crate::cache::Cache;
+use crate::http::HttpClient;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::{
+    StatusCode, Url,
+    header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT},
+};
+use serde::Deserialize;
+use std::{env, time::Duration};
+
+const DEFAULT_HOST: &str = "https://gitlab.com";
+
+pub struct GitLabProvider {
+    client: reqwest::Client,
+    http: HttpClient,
+    api_base: String,
+    web_base: String,
+    cache: Cache,
+}
+
+impl GitLabProvider {
+    pub fn new(host: Option<&str>, cache: Cache) -> Result<Self> {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static("grit-find"));
+
+        if let Ok(token) = env::var("GITLAB_TOKEN") {
+            let mut value = HeaderValue::from_str(&token)?;
+            value.set_sensitive(true);
+            headers.insert(HeaderName::from_static("private-token"), value);
+        }
+
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(Duration::from_secs(20))
+            .build()
+            .context("failed to build GitLab HTTP client")?;
+
+        let host = host.unwrap_or(DEFAULT_HOST).trim_end_matches('/').to_string();
+        Ok(Self {
+            http: HttpClient::new(client.clone()),
+            client,
+            api_base: format!("{host}/api/v4"),
+            web_base: host,
+            cache,
+        })
+    }
+
+    /// GET `url`, transparently serving a fresh cache entry if present. On a
+    /// stale or missing entry, revalidates with `If-None-Match` when we have
+    /// a stored ETag: a `304` just refreshes the entry's age and reuses the
+    /// cached body, while a `200` overwrites it.
+    async fn get_cached(&self, url: Url) -> Result<String> {
+        if let Some(body) = self.cache.get(url.as_str()) {
+            return Ok(body);
+        }
+        let etag = self.cache.etag(url.as_str());
+
+        let res = self.http.get_conditional(url.clone(), etag.as_deref()).await?;
+        if res.status == StatusCode::NOT_MODIFIED {
+            if let Some(body) = self.cache.get_stale(url.as_str()) {
+                self.cache.touch(url.as_str())?;
+                return Ok(body);
+            }
+        }
+
+        let res = res.error_for_status()?;
+        let etag = res.header("etag").map(str::to_string);
+        self.cache.put(url.as_str(), &res.body, etag.as_deref())?;
+        Ok(res.body)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabProject {
+    path_with_namespace: String,
+    description: Option<String>,
+    star_count: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabRelease {
+    tag_name: String,
+    name: Option<String>,
+    assets: GitLabReleaseAssets,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabReleaseAssets {
+    links: Vec<GitLabAssetLink>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabAssetLink {
+    name: String,
+    url: String,
+}
+
+#[async_trait]
+impl ForgeProvider for GitLabProvider {
+    async fn search_repos(&self, query: &str, per_page: usize, page: usize) -> Result<Vec<Repo>> {
+        let url = Url::parse_with_params(
+            &format!("{}/projects", self.api_base),
+            [
+                ("search", query),
+                ("per_page", &per_page.to_string()),
+                ("page", &page.to_string()),
+                ("order_by", "star_count"),
+                ("sort", "desc"),
+                ("visibility", "public"),
+            ],
+        )?;
+
+        let body = self
+            .get_cached(url)
+            .await
+            .context("GitLab project search failed")?;
+        let projects: Vec<GitLabProject> = serde_json::from_str(&body)?;
+        Ok(projects
+            .into_iter()
+            .map(|p| Repo {
+                full_name: p.path_with_namespace,
+                description: p.description,
+                stargazers_count: p.star_count,
+            })
+            .collect())
+    }
+
+    async fn latest_release(&self, full_name: &str) -> Result<Release> {
+        let mut url = Url::parse(&format!("{}/projects/", self.api_base))?;
+        url.path_segments_mut()
+            .map_err(|_| anyhow::anyhow!("invalid GitLab API base URL"))?
+            .push(full_name)
+            .push("releases")
+            .push("permalink")
+            .push("latest");
+        let body = self
+            .get_cached(url)
+            .await
+            .with_context(|| format!("Failed to fetch latest release for {full_name}"))?;
+        let release: GitLabRelease = serde_json::from_str(&body)?;
+        Ok(Release {
+            tag_name: release.tag_name,
+            name: release.name,
+            assets: release
+                .assets
+                .links
+                .into_iter()
+                .map(|link| Asset {
+                    name: link.name,
+                    download_url: link.url,
+                    // GitLab's release-links API doesn't report a size; the
+                    // progress bar just won't show a total for these.
+                    size: 0,
+                })
+                .collect(),
+        })
+    }
+
+    fn clone_url(&self, full_name: &str) -> String {
+        format!("{}/{full_name}.git", self.web_base)
+    }
+
+    fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+}
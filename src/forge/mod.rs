@@ -0,0 +1,89 @@
+//! Forge-agnostic types and the [`ForgeProvider`] trait that each backend
+//! (GitHub, GitLab, Gitea, ...) implements.
+
+mod gitea;
+mod github;
+mod gitlab;
+
+use crate::cache::Cache;
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use clap::ValueEnum;
+use serde::Deserialize;
+
+pub use gitea::GiteaProvider;
+pub use github::GitHubProvider;
+pub use gitlab::GitLabProvider;
+
+/// Which forge backend to talk to, selected via `--forge`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Forge {
+    Github,
+    Gitlab,
+    Gitea,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Repo {
+    pub full_name: String,
+    pub description: Option<String>,
+    pub stargazers_count: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub assets: Vec<Asset>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Asset {
+    pub name: String,
+    pub download_url: String,
+    pub size: u64,
+}
+
+/// A single forge backend: searching repos, looking up releases, and
+/// resolving the URL to download a release asset from.
+#[async_trait]
+pub trait ForgeProvider {
+    /// Search repositories matching `query`, returning one page of results.
+    async fn search_repos(&self, query: &str, per_page: usize, page: usize) -> Result<Vec<Repo>>;
+
+    /// Fetch the latest release for `full_name` (e.g. `owner/repo`).
+    async fn latest_release(&self, full_name: &str) -> Result<Release>;
+
+    /// Resolve the URL an asset should be downloaded from.
+    fn download_url(&self, asset: &Asset) -> String {
+        asset.download_url.clone()
+    }
+
+    /// Resolve the `git clone` URL for `full_name` (e.g. `owner/repo`).
+    fn clone_url(&self, full_name: &str) -> String;
+
+    /// The HTTP client configured with this backend's auth/headers, reused
+    /// for asset downloads so private releases stay authenticated.
+    fn client(&self) -> &reqwest::Client;
+}
+
+/// Build the provider selected by `forge`, pointed at `host` if given
+/// (otherwise github.com or gitlab.com; Gitea has no default and errors
+/// without `host`), sharing `cache` for GET responses.
+pub fn build_provider(
+    forge: Forge,
+    host: Option<&str>,
+    cache: Cache,
+) -> Result<Box<dyn ForgeProvider>> {
+    match forge {
+        Forge::Github => Ok(Box::new(GitHubProvider::new(host, cache)?)),
+        Forge::Gitlab => Ok(Box::new(GitLabProvider::new(host, cache)?)),
+        Forge::Gitea => Ok(Box::new(GiteaProvider::new(host, cache)?)),
+    }
+}
+
+pub(crate) fn require_host<'a>(forge_name: &str, host: Option<&'a str>) -> Result<&'a str> {
+    host.ok_or_else(|| {
+        anyhow!("--forge {forge_name} requires --host <instance-url> (e.g. https://gitea.example.com)")
+    })
+}
@@ -0,0 +1,155 @@
+//! Thin wrapper around [`reqwest::Client`] that can record real HTTP
+//! exchanges to disk and replay them later without touching the network.
+//!
+//! Set `GRIT_FIND_RECORD=<dir>` to have every GET request's method, URL,
+//! status, and body written to a fixture file under `<dir>`. Set
+//! `GRIT_FIND_REPLAY=<dir>` to serve requests from fixtures recorded the
+//! same way instead of hitting the network — used by the integration tests
+//! to exercise paging, rate-limit backoff, and release filtering offline.
+
+use anyhow::{Context, Result, anyhow};
+use reqwest::{StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, hash_map::DefaultHasher},
+    env,
+    hash::{Hash, Hasher},
+    path::PathBuf,
+    sync::Mutex,
+};
+
+#[derive(Serialize, Deserialize)]
+struct Fixture {
+    method: String,
+    url: String,
+    status: u16,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    body: String,
+}
+
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_lowercase()).map(String::as_str)
+    }
+}
+
+impl HttpResponse {
+    pub fn error_for_status(self) -> Result<Self> {
+        if self.status.is_client_error() || self.status.is_server_error() {
+            Err(anyhow!("HTTP {} ({} bytes)", self.status, self.body.len()))
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+pub struct HttpClient {
+    inner: reqwest::Client,
+    record_dir: Option<PathBuf>,
+    replay_dir: Option<PathBuf>,
+    // Same URL can be fetched more than once in a single run (e.g. a
+    // rate-limit retry), so fixtures for a key are numbered in call order.
+    call_counts: Mutex<HashMap<String, u32>>,
+}
+
+impl HttpClient {
+    pub fn new(inner: reqwest::Client) -> Self {
+        Self {
+            inner,
+            record_dir: env::var("GRIT_FIND_RECORD").ok().map(PathBuf::from),
+            replay_dir: env::var("GRIT_FIND_REPLAY").ok().map(PathBuf::from),
+            call_counts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        self.replay_dir.is_some()
+    }
+
+    pub async fn get(&self, url: Url) -> Result<HttpResponse> {
+        self.get_conditional(url, None).await
+    }
+
+    /// Like [`get`](Self::get), but sends `If-None-Match: if_none_match`
+    /// when given, so a server holding the same ETag can answer with a
+    /// cheap `304 Not Modified` instead of resending the body.
+    pub async fn get_conditional(&self, url: Url, if_none_match: Option<&str>) -> Result<HttpResponse> {
+        let seq = self.next_seq("GET", url.as_str());
+
+        if let Some(dir) = &self.replay_dir {
+            let path = fixture_path(dir, "GET", url.as_str(), seq);
+            let data = std::fs::read(&path).with_context(|| {
+                format!(
+                    "no replay fixture for GET {url} (call #{seq}, looked in {})",
+                    path.display()
+                )
+            })?;
+            let fixture: Fixture = serde_json::from_slice(&data)?;
+            return Ok(HttpResponse {
+                status: StatusCode::from_u16(fixture.status)?,
+                headers: fixture.headers,
+                body: fixture.body,
+            });
+        }
+
+        let mut req = self.inner.get(url.clone());
+        if let Some(etag) = if_none_match {
+            req = req.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+        let res = req.send().await?;
+        let status = res.status();
+        let headers: HashMap<String, String> = res
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                Some((name.as_str().to_lowercase(), value.to_str().ok()?.to_string()))
+            })
+            .collect();
+        let body = res.text().await?;
+
+        if let Some(dir) = &self.record_dir {
+            std::fs::create_dir_all(dir)?;
+            let fixture = Fixture {
+                method: "GET".to_string(),
+                url: url.to_string(),
+                status: status.as_u16(),
+                headers: headers.clone(),
+                body: body.clone(),
+            };
+            std::fs::write(
+                fixture_path(dir, "GET", url.as_str(), seq),
+                serde_json::to_vec_pretty(&fixture)?,
+            )?;
+        }
+
+        Ok(HttpResponse { status, headers, body })
+    }
+
+    fn next_seq(&self, method: &str, url: &str) -> u32 {
+        let mut counts = self.call_counts.lock().expect("call_counts lock poisoned");
+        let seq = counts.entry(format!("{method} {url}")).or_insert(0);
+        let this_call = *seq;
+        *seq += 1;
+        this_call
+    }
+}
+
+fn fixture_path(dir: &std::path::Path, method: &str, url: &str, seq: u32) -> PathBuf {
+    dir.join(fixture_filename(method, url, seq))
+}
+
+/// The fixture file name `HttpClient` will look for on its `seq`'th (0-based)
+/// call to `method url`. Exposed so tests can write fixtures without
+/// duplicating the hashing scheme.
+pub fn fixture_filename(method: &str, url: &str, seq: u32) -> String {
+    let mut hasher = DefaultHasher::new();
+    (method, url).hash(&mut hasher);
+    format!("{:016x}-{seq}.json", hasher.finish())
+}
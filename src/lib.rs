@@ -0,0 +1,6 @@
+pub mod ai;
+pub mod cache;
+pub mod checksum;
+pub mod forge;
+pub mod http;
+pub mod platform;
@@ -1,29 +1,47 @@
 use anyhow::{Context, Result, anyhow};
 use clap::Parser;
-use dialoguer::{Input, Select, theme::ColorfulTheme};
-use futures_util::StreamExt;
+use dialoguer::{Input, theme::ColorfulTheme};
+use futures_util::{StreamExt, stream};
 use indicatif::{ProgressBar, ProgressStyle};
-use reqwest::{
-    StatusCode, Url,
-    header::{ACCEPT, AUTHORIZATION, HeaderMap, HeaderValue, USER_AGENT},
-};
-use serde::Deserialize;
-use std::{env, fs::File, io::Write, path::PathBuf, time::Duration};
-use tokio::time::sleep;
+use sha2::{Digest, Sha256};
+use std::{fs::File, io::Write, path::PathBuf, sync::Arc, time::Duration};
+use tokio::sync::Semaphore;
+
+use grit_find::ai::{AiBackend, build_suggester};
+use grit_find::cache::Cache;
+use grit_find::forge::{Asset, Forge, ForgeProvider, Repo, build_provider};
+use grit_find::{checksum, platform};
 
 const DISPLAY_PAGE_SIZE: usize = 25; // show 25 per page
 const MAX_RESULTS: usize = 100; // fetch up to 100 total results
 
 #[derive(Parser, Debug)]
-#[command(version, about = "Search and download GitHub releases")]
+#[command(version, about = "Search and download releases from GitHub, GitLab, or Gitea")]
 struct Cli {
     /// Search terms (fallback to interactive prompt)
     query: Vec<String>,
 
-    /// Use OpenAI to help craft the search query from a short description
+    /// Use an AI backend to help craft the search query from a short description
     #[arg(long)]
     ai: bool,
 
+    /// Which AI backend to use with --ai
+    #[arg(long, value_enum, default_value_t = AiBackend::Openai)]
+    ai_backend: AiBackend,
+
+    /// Override the AI backend's default model
+    #[arg(long)]
+    ai_model: Option<String>,
+
+    /// Which forge to search
+    #[arg(long, value_enum, default_value_t = Forge::Github)]
+    forge: Forge,
+
+    /// Self-hosted/enterprise instance URL (e.g. https://gitlab.example.com).
+    /// Required for --forge gitea; optional override for github/gitlab.
+    #[arg(long)]
+    host: Option<String>,
+
     /// Destination directory for downloaded asset
     #[arg(short, long, value_name = "DIR", default_value = ".")]
     output: PathBuf,
@@ -31,32 +49,48 @@ struct Cli {
     /// 1-based display page to start from (each page shows up to 25 results)
     #[arg(long, default_value_t = 1)]
     page: usize,
-}
 
-#[derive(Debug, Deserialize)]
-struct SearchResponse {
-    items: Vec<Repo>,
-}
+    /// How long cached search/release responses stay fresh, in seconds
+    #[arg(long, default_value_t = 3600)]
+    cache_ttl: u64,
 
-#[derive(Debug, Deserialize, Clone)]
-struct Repo {
-    full_name: String,
-    description: Option<String>,
-    stargazers_count: u64,
-}
+    /// Bypass the on-disk response cache entirely
+    #[arg(long)]
+    no_cache: bool,
 
-#[derive(Debug, Deserialize)]
-struct Release {
-    tag_name: String,
-    name: Option<String>,
-    assets: Vec<Asset>,
-}
+    /// Number of concurrent release-existence probes per search page
+    #[arg(long, default_value_t = 12)]
+    concurrency: usize,
+
+    /// Only keep repos with at least this many stars
+    #[arg(long)]
+    min_stars: Option<u64>,
+
+    /// Only keep repos primarily written in this language (--forge github only)
+    #[arg(long)]
+    language: Option<String>,
+
+    /// Only keep repos tagged with this topic (--forge github only)
+    #[arg(long)]
+    topic: Option<String>,
+
+    /// Skip the asset prompt and download the asset that best matches this
+    /// machine's OS/arch, erroring if nothing scores above zero
+    #[arg(long)]
+    auto: bool,
+
+    /// Skip SHA-256 verification against a release's checksum manifest
+    #[arg(long)]
+    no_verify: bool,
 
-#[derive(Debug, Deserialize, Clone)]
-struct Asset {
-    name: String,
-    browser_download_url: String,
-    size: u64,
+    /// Clone the selected repo(s) into --output instead of downloading a
+    /// release asset
+    #[arg(long)]
+    clone: bool,
+
+    /// After cloning (--clone), spawn $SHELL in the checkout so you land in it
+    #[arg(long, requires = "clone")]
+    shell: bool,
 }
 
 #[tokio::main]
@@ -67,24 +101,47 @@ async fn main() -> Result<()> {
     let query = if args.ai {
         let description: String = if args.query.is_empty() {
             Input::with_theme(&theme)
-                .with_prompt("Describe what you need (OpenAI will craft the search query)")
+                .with_prompt(format!(
+                    "Describe what you need ({:?} will craft the search query)",
+                    args.ai_backend
+                ))
                 .interact_text()?
         } else {
             args.query.join(" ")
         };
-        println!("Using OpenAI to propose a GitHub search query...");
-        ai_suggest_query(&description).await?
+        println!("Using {:?} to propose a search query...", args.ai_backend);
+        let suggester = build_suggester(args.ai_backend, args.ai_model.as_deref())?;
+        suggester.suggest(&description, args.forge).await?
     } else if args.query.is_empty() {
         Input::with_theme(&theme)
-            .with_prompt("GitHub search keywords")
+            .with_prompt("Search keywords")
             .interact_text()?
     } else {
         args.query.join(" ")
     };
+    let query = if args.forge == Forge::Github {
+        apply_filter_qualifiers(&query, args.min_stars, &args.language, &args.topic)
+    } else {
+        if args.language.is_some() || args.topic.is_some() {
+            eprintln!(
+                "Warning: --language and --topic are GitHub search qualifiers and are ignored for --forge {:?}",
+                args.forge
+            );
+        }
+        query
+    };
 
-    let github = github_client()?;
+    let cache = Cache::new(Duration::from_secs(args.cache_ttl), args.no_cache);
+    let provider = build_provider(args.forge, args.host.as_deref(), cache)?;
     // Fetch up to MAX_RESULTS once; paging is local (no extra API calls)
-    let repos = fetch_all_repos(&github, &query).await?;
+    let repos = fetch_all_repos(
+        provider.as_ref(),
+        &query,
+        args.concurrency,
+        args.min_stars,
+        !args.clone,
+    )
+    .await?;
     if repos.is_empty() {
         println!("No repositories found for query: {query}");
         return Ok(());
@@ -93,12 +150,12 @@ async fn main() -> Result<()> {
     let total_pages = (repos.len() + DISPLAY_PAGE_SIZE - 1) / DISPLAY_PAGE_SIZE;
     let mut page = args.page.max(1).min(total_pages.max(1));
 
-    let repo = loop {
+    let selected_repos = loop {
         let start = (page - 1) * DISPLAY_PAGE_SIZE;
         let end = (start + DISPLAY_PAGE_SIZE).min(repos.len());
         let slice = &repos[start..end];
         println!(
-            "\nShowing page {}/{} ({} results this page, total {}). Enter number to select, 'n' for next page, 'p' for previous page, or 'c' to cancel.",
+            "\nShowing page {}/{} ({} results this page, total {}). Enter number(s) to select (e.g. '1 3 5-8'), 'n' for next page, 'p' for previous page, or 'c' to cancel.",
             page,
             total_pages,
             slice.len(),
@@ -118,7 +175,7 @@ async fn main() -> Result<()> {
         }
 
         let choice: String = Input::with_theme(&theme)
-            .with_prompt("Choice (number, n/p, c)")
+            .with_prompt("Choice (number(s)/ranges, n/p, c)")
             .interact_text()?;
         let choice = choice.trim();
 
@@ -140,137 +197,218 @@ async fn main() -> Result<()> {
             return Ok(());
         }
 
-        if let Ok(num) = choice.parse::<usize>() {
-            if num >= 1 && num <= slice.len() {
-                break slice[num - 1].clone();
+        match parse_index_ranges(choice, slice.len()) {
+            Ok(indices) if !indices.is_empty() => {
+                break indices.into_iter().map(|i| slice[i].clone()).collect::<Vec<_>>();
             }
+            Ok(_) => println!("Enter at least one selection."),
+            Err(e) => println!("{e} (enter a number between 1-{}, n, p, or c)", slice.len()),
         }
-
-        println!(
-            "Invalid choice. Please enter a number between 1-{}, n, p, or c.",
-            slice.len()
-        );
     };
-    let release = latest_release(&github, &repo.full_name).await?;
-    if release.assets.is_empty() {
-        println!(
-            "Latest release '{}' has no downloadable assets.",
-            release.tag_name
-        );
+
+    let dest_dir = &args.output;
+    tokio::fs::create_dir_all(dest_dir).await?;
+
+    if args.clone {
+        for repo in &selected_repos {
+            let dest = clone_repo(provider.as_ref(), repo, dest_dir).await?;
+            if args.shell {
+                spawn_shell_in(&dest).await?;
+            }
+        }
+        println!("Done.");
         return Ok(());
     }
 
-    let asset_choices: Vec<String> = release
-        .assets
-        .iter()
-        .map(|a| format!("{} ({:.2} MB)", a.name, a.size as f64 / 1_048_576.0))
-        .collect();
+    for repo in &selected_repos {
+        let release = provider.latest_release(&repo.full_name).await?;
+        if release.assets.is_empty() {
+            println!(
+                "{}: latest release '{}' has no downloadable assets.",
+                repo.full_name, release.tag_name
+            );
+            continue;
+        }
 
-    let asset_idx = Select::with_theme(&theme)
-        .with_prompt(format!(
-            "Select asset to download from {} ({})",
+        println!(
+            "\n{} — {} ({})",
+            repo.full_name,
             release.tag_name,
             release
                 .name
                 .clone()
                 .unwrap_or_else(|| "unnamed release".into())
-        ))
-        .items(&asset_choices)
-        .default(0)
-        .interact()?;
+        );
 
-    let asset = release.assets[asset_idx].clone();
-    let dest_dir = &args.output;
-    tokio::fs::create_dir_all(dest_dir).await?;
-    let dest = dest_dir.join(&asset.name);
+        let best = platform::best_match(&release.assets);
+
+        let indices = if args.auto {
+            let idx = best.ok_or_else(|| {
+                anyhow!(
+                    "{}: no release asset matches this machine's OS/arch (--auto)",
+                    repo.full_name
+                )
+            })?;
+            println!("Auto-selected {}", release.assets[idx].name);
+            vec![idx]
+        } else {
+            for (idx, a) in release.assets.iter().enumerate() {
+                let marker = if Some(idx) == best { " (this machine)" } else { "" };
+                println!(
+                    "{:>3}. {} ({:.2} MB){marker}",
+                    idx + 1,
+                    a.name,
+                    a.size as f64 / 1_048_576.0
+                );
+            }
 
-    println!("Downloading {} to {}", asset.name, dest.to_string_lossy());
-    download_asset(&github, &asset, &dest).await?;
+            let mut prompt = Input::with_theme(&theme)
+                .with_prompt("Select asset(s) to download (e.g. '1 3 5-8')");
+            if let Some(idx) = best {
+                prompt = prompt.with_initial_text((idx + 1).to_string());
+            }
+            let choice: String = prompt.interact_text()?;
+            let indices = parse_index_ranges(choice.trim(), release.assets.len())?;
+            if indices.is_empty() {
+                println!("No assets selected for {}, skipping.", repo.full_name);
+                continue;
+            }
+            indices
+        };
+
+        let manifest = if args.no_verify {
+            None
+        } else {
+            match checksum::find_manifest(&release.assets) {
+                Some(manifest_asset) => {
+                    let text = provider
+                        .client()
+                        .get(provider.download_url(manifest_asset))
+                        .send()
+                        .await?
+                        .error_for_status()?
+                        .text()
+                        .await?;
+                    Some(checksum::parse_manifest(&text))
+                }
+                None => None,
+            }
+        };
+
+        for idx in indices {
+            let asset = &release.assets[idx];
+            let dest = dest_dir.join(&asset.name);
+            println!("Downloading {} to {}", asset.name, dest.to_string_lossy());
+            let digest = download_asset(provider.as_ref(), asset, &dest).await?;
+
+            match manifest.as_ref().map(|m| m.get(&asset.name)) {
+                Some(Some(expected)) if *expected == digest => {
+                    println!("Checksum OK for {}", asset.name);
+                }
+                Some(Some(expected)) => {
+                    anyhow::bail!(
+                        "Checksum mismatch for {}: expected {expected}, got {digest}",
+                        asset.name
+                    );
+                }
+                Some(None) => {
+                    println!(
+                        "Warning: {} is not listed in the release's checksum manifest, skipping verification",
+                        asset.name
+                    );
+                }
+                None => {}
+            }
+        }
+    }
     println!("Done.");
 
     Ok(())
 }
 
-fn github_client() -> Result<reqwest::Client> {
-    let mut headers = HeaderMap::new();
-    headers.insert(USER_AGENT, HeaderValue::from_str("grit-find (github.com)")?);
-    headers.insert(
-        ACCEPT,
-        HeaderValue::from_static("application/vnd.github+json"),
-    );
-
-    if let Ok(token) = env::var("GITHUB_TOKEN") {
-        let mut value = HeaderValue::from_str(&format!("Bearer {}", token))?;
-        value.set_sensitive(true);
-        headers.insert(AUTHORIZATION, value);
+/// Parse a space- and dash-separated selection like `"1 3 5-8"` into a
+/// deduplicated, sorted set of 0-based indices, validated against `count`
+/// 1-based items.
+fn parse_index_ranges(input: &str, count: usize) -> Result<Vec<usize>> {
+    let mut indices = std::collections::BTreeSet::new();
+    for token in input.split_whitespace() {
+        let (start, end) = match token.split_once('-') {
+            Some((start, end)) => (
+                start
+                    .parse::<usize>()
+                    .map_err(|_| anyhow!("invalid selection '{token}'"))?,
+                end.parse::<usize>()
+                    .map_err(|_| anyhow!("invalid selection '{token}'"))?,
+            ),
+            None => {
+                let n = token
+                    .parse::<usize>()
+                    .map_err(|_| anyhow!("invalid selection '{token}'"))?;
+                (n, n)
+            }
+        };
+        if start == 0 || end == 0 || start > end {
+            return Err(anyhow!("invalid selection '{token}'"));
+        }
+        for n in start..=end {
+            if n > count {
+                return Err(anyhow!("selection {n} is out of range (1-{count})"));
+            }
+            indices.insert(n - 1);
+        }
     }
-
-    reqwest::Client::builder()
-        .default_headers(headers)
-        .user_agent("grit-find")
-        .timeout(Duration::from_secs(20))
-        .build()
-        .context("failed to build GitHub HTTP client")
+    Ok(indices.into_iter().collect())
 }
 
 async fn fetch_repos_page(
-    client: &reqwest::Client,
+    provider: &dyn ForgeProvider,
     query: &str,
     per_page: usize,
     page: usize,
+    concurrency: usize,
+    probe_releases: bool,
 ) -> Result<Vec<Repo>> {
-    let q = format!("{query} is:public");
-    let url = Url::parse_with_params(
-        "https://api.github.com/search/repositories",
-        [
-            ("q", q.as_str()),
-            ("per_page", &per_page.to_string()),
-            ("page", &page.to_string()),
-            ("sort", "stars"),
-            ("order", "desc"),
-        ],
-    )?;
-
-    let mut attempts = 0;
-    loop {
-        attempts += 1;
-        let res = client.get(url.clone()).send().await?;
-        if res.status() == StatusCode::TOO_MANY_REQUESTS {
-            let wait = res
-                .headers()
-                .get("retry-after")
-                .and_then(|v| v.to_str().ok())
-                .and_then(|s| s.parse::<u64>().ok())
-                .unwrap_or(5);
-            println!("Hit GitHub rate limit. Waiting {wait} seconds...");
-            sleep(Duration::from_secs(wait)).await;
-            if attempts < 3 {
-                continue;
-            }
-        }
-
-        let res = res.error_for_status().context("GitHub search failed")?;
-        let search: SearchResponse = res.json().await?;
-
-        // Filter by having a release available
-        let mut with_releases = Vec::new();
-        for repo in search.items {
-            if let Ok(_) = latest_release(client, &repo.full_name).await {
-                with_releases.push(repo);
+    let items = provider.search_repos(query, per_page, page).await?;
+    if !probe_releases {
+        // --clone doesn't care whether a repo has published releases, so
+        // skip the probe entirely instead of wasting N API calls filtering
+        // out repos that `git clone` would have happily cloned anyway.
+        return Ok(items);
+    }
+    let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+
+    // Probe release existence concurrently, but keep the star-sorted order
+    // the search API returned by tagging each repo with its original index.
+    let mut probed: Vec<(usize, Option<Repo>)> = stream::iter(items.into_iter().enumerate())
+        .map(|(idx, repo)| {
+            let semaphore = Arc::clone(&semaphore);
+            async move {
+                let _permit = semaphore.acquire().await.expect("semaphore never closed");
+                let has_release = provider.latest_release(&repo.full_name).await.is_ok();
+                (idx, has_release.then_some(repo))
             }
-        }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
 
-        return Ok(with_releases);
-    }
+    probed.sort_by_key(|(idx, _)| *idx);
+    Ok(probed.into_iter().filter_map(|(_, repo)| repo).collect())
 }
 
-async fn fetch_all_repos(client: &reqwest::Client, query: &str) -> Result<Vec<Repo>> {
+async fn fetch_all_repos(
+    provider: &dyn ForgeProvider,
+    query: &str,
+    concurrency: usize,
+    min_stars: Option<u64>,
+    probe_releases: bool,
+) -> Result<Vec<Repo>> {
     let mut all = Vec::new();
     let mut page = 1;
     let per_page = 100; // minimize API calls
 
     while all.len() < MAX_RESULTS {
-        let fetched = fetch_repos_page(client, query, per_page, page).await?;
+        let fetched = fetch_repos_page(provider, query, per_page, page, concurrency, probe_releases).await?;
         if fetched.is_empty() {
             break;
         }
@@ -285,23 +423,96 @@ async fn fetch_all_repos(client: &reqwest::Client, query: &str) -> Result<Vec<Re
         }
         page += 1;
     }
+
+    // For --forge github, --min-stars is also sent as a query qualifier;
+    // enforce it here too as a hard cut, and for the forges that never see it.
+    if let Some(min_stars) = min_stars {
+        all.retain(|r| r.stargazers_count >= min_stars);
+    }
+
     Ok(all)
 }
 
-async fn latest_release(client: &reqwest::Client, full_name: &str) -> Result<Release> {
-    let url = format!("https://api.github.com/repos/{full_name}/releases/latest");
-    let res = client
-        .get(&url)
-        .send()
-        .await?
-        .error_for_status()
-        .with_context(|| format!("Failed to fetch latest release for {full_name}"))?;
-    Ok(res.json::<Release>().await?)
+/// Clone `repo` into `dest_dir` (named after its last path segment), showing
+/// a spinner while `git clone` runs. Skips the clone entirely if the
+/// destination already exists.
+async fn clone_repo(provider: &dyn ForgeProvider, repo: &Repo, dest_dir: &PathBuf) -> Result<PathBuf> {
+    let name = repo.full_name.rsplit('/').next().unwrap_or(&repo.full_name);
+    let dest = dest_dir.join(name);
+
+    if dest.exists() {
+        println!("{} already exists at {}, skipping clone.", repo.full_name, dest.to_string_lossy());
+        return Ok(dest);
+    }
+
+    let pb = ProgressBar::new_spinner();
+    pb.set_style(ProgressStyle::with_template("{spinner:.green} {msg}")?);
+    pb.enable_steady_tick(Duration::from_millis(100));
+    pb.set_message(format!("Cloning {}...", repo.full_name));
+
+    let status = tokio::process::Command::new("git")
+        .arg("clone")
+        .arg(provider.clone_url(&repo.full_name))
+        .arg(&dest)
+        .status()
+        .await
+        .context("failed to run `git clone` (is git installed?)")?;
+
+    pb.finish_and_clear();
+
+    if !status.success() {
+        anyhow::bail!("git clone of {} failed", repo.full_name);
+    }
+
+    println!("Cloned {} to {}", repo.full_name, dest.to_string_lossy());
+    Ok(dest)
+}
+
+/// Spawn the user's `$SHELL` (falling back to `/bin/sh`) in `dir`, waiting
+/// for it to exit before returning so the caller lands back in grit-find.
+async fn spawn_shell_in(dir: &PathBuf) -> Result<()> {
+    let shell = std::env::var("SHELL").unwrap_or_else(|_| "/bin/sh".to_string());
+    println!("Spawning {shell} in {}...", dir.to_string_lossy());
+    tokio::process::Command::new(shell)
+        .current_dir(dir)
+        .status()
+        .await
+        .context("failed to spawn shell")?;
+    Ok(())
+}
+
+/// Fold `--min-stars`/`--language`/`--topic` into GitHub search qualifiers
+/// appended to the user's query string. GitHub-specific: other forges don't
+/// understand this syntax, so callers should only use this for `--forge github`.
+fn apply_filter_qualifiers(
+    query: &str,
+    min_stars: Option<u64>,
+    language: &Option<String>,
+    topic: &Option<String>,
+) -> String {
+    let mut q = query.to_string();
+    if let Some(min_stars) = min_stars {
+        q.push_str(&format!(" stars:>={min_stars}"));
+    }
+    if let Some(language) = language {
+        q.push_str(&format!(" language:{language}"));
+    }
+    if let Some(topic) = topic {
+        q.push_str(&format!(" topic:{topic}"));
+    }
+    q
 }
 
-async fn download_asset(client: &reqwest::Client, asset: &Asset, dest: &PathBuf) -> Result<()> {
-    let resp = client
-        .get(&asset.browser_download_url)
+/// Download `asset` to `dest`, returning the lowercase hex SHA-256 digest of
+/// its bytes (computed during the same streaming pass, no second read).
+async fn download_asset(
+    provider: &dyn ForgeProvider,
+    asset: &Asset,
+    dest: &PathBuf,
+) -> Result<String> {
+    let resp = provider
+        .client()
+        .get(provider.download_url(asset))
         .send()
         .await?
         .error_for_status()
@@ -317,126 +528,42 @@ async fn download_asset(client: &reqwest::Client, asset: &Asset, dest: &PathBuf)
     );
 
     let mut file = File::create(dest).context("create destination file")?;
+    let mut hasher = Sha256::new();
     let mut stream = resp.bytes_stream();
     while let Some(chunk) = stream.next().await {
         let chunk = chunk?;
         file.write_all(&chunk)?;
+        hasher.update(&chunk);
         pb.inc(chunk.len() as u64);
     }
     pb.finish_with_message("downloaded");
-    Ok(())
-}
-
-async fn ai_suggest_query(description: &str) -> Result<String> {
-    use async_openai::{
-        Client,
-        types::{
-            ChatCompletionRequestMessage, ChatCompletionRequestUserMessage,
-            ChatCompletionRequestUserMessageContent, CreateChatCompletionRequestArgs,
-            ResponseFormat,
-        },
-    };
-    let client = Client::new();
-    let prompt = format!(
-        "You are helping craft a concise GitHub search query to find repositories with releases. \
-Description: \"{}\". Respond as JSON: {{\"query\": \"...\"}} with no extra text.",
-        description
-    );
-
-    let user_msg = ChatCompletionRequestMessage::User(ChatCompletionRequestUserMessage {
-        content: ChatCompletionRequestUserMessageContent::Text(prompt),
-        name: None,
-    });
-
-    let req = CreateChatCompletionRequestArgs::default()
-        .model("gpt-4o-mini")
-        .messages([user_msg])
-        // Ask the API to emit strict JSON; still defensively parse below.
-        .response_format(ResponseFormat::JsonObject)
-        .build()?;
-
-    let resp = client.chat().create(req).await?;
-    let content = resp
-        .choices
-        .first()
-        .and_then(|c| c.message.content.as_ref())
-        .ok_or_else(|| anyhow!("OpenAI returned empty content"))?;
-
-    parse_query_from_content(content)
-}
-
-#[derive(Deserialize)]
-struct Suggestion {
-    query: String,
-}
-
-fn parse_query_from_content(content: &str) -> Result<String> {
-    // Try as-is first.
-    if let Ok(s) = serde_json::from_str::<Suggestion>(content) {
-        return Ok(s.query);
-    }
-
-    // Handle common model behaviour of wrapping JSON in ```json fences.
-    let trimmed = content.trim();
-    if let Some(stripped) = strip_code_fence(trimmed) {
-        if let Ok(s) = serde_json::from_str::<Suggestion>(&stripped) {
-            return Ok(s.query);
-        }
-    }
-
-    Err(anyhow!(
-        "OpenAI response was not valid JSON (first 200 chars): {}",
-        truncate_preview(content, 200)
-    ))
+    Ok(hex::encode(hasher.finalize()))
 }
 
-fn strip_code_fence(input: &str) -> Option<String> {
-    if !input.starts_with("```") {
-        return None;
-    }
-
-    let mut lines = input.lines();
-    // Drop opening fence (maybe with language tag).
-    lines.next()?;
-
-    let mut body_lines = Vec::new();
-    for line in lines {
-        if line.trim() == "```" {
-            break;
-        }
-        body_lines.push(line);
-    }
+#[cfg(test)]
+mod tests {
+    use super::parse_index_ranges;
 
-    if body_lines.is_empty() {
-        return None;
+    #[test]
+    fn parses_mixed_ranges_and_singletons() {
+        let indices = parse_index_ranges("1 3 5-8", 10).expect("should parse");
+        assert_eq!(indices, vec![0, 2, 4, 5, 6, 7]);
     }
 
-    Some(body_lines.join("\n").trim().to_string())
-}
-
-fn truncate_preview(content: &str, max_chars: usize) -> String {
-    let mut preview = content.chars().take(max_chars).collect::<String>();
-    if content.chars().count() > max_chars {
-        preview.push_str("…");
+    #[test]
+    fn dedups_overlapping_selections() {
+        let indices = parse_index_ranges("2 1-3 3", 5).expect("should parse");
+        assert_eq!(indices, vec![0, 1, 2]);
     }
-    preview
-}
-
-#[cfg(test)]
-mod tests {
-    use super::parse_query_from_content;
 
     #[test]
-    fn parses_plain_json() {
-        let content = r#"{"query":"foo bar"}"#;
-        let q = parse_query_from_content(content).expect("should parse plain json");
-        assert_eq!(q, "foo bar");
+    fn rejects_out_of_range_selection() {
+        assert!(parse_index_ranges("5", 3).is_err());
     }
 
     #[test]
-    fn parses_code_fenced_json() {
-        let content = "```json\n{\"query\":\"ripgrep\"}\n```";
-        let q = parse_query_from_content(content).expect("should parse fenced json");
-        assert_eq!(q, "ripgrep");
+    fn rejects_zero_and_garbage() {
+        assert!(parse_index_ranges("0", 3).is_err());
+        assert!(parse_index_ranges("abc", 3).is_err());
     }
 }
@@ -0,0 +1,80 @@
+//! Best-effort matching of a release asset to the machine grit-find is
+//! running on, used to preselect (or `--auto` pick) the right download.
+
+use crate::forge::Asset;
+
+/// Score how well `name` matches the current OS/arch, or `0` if it
+/// doesn't look like it targets this machine at all.
+fn score(name: &str) -> i32 {
+    let lower = name.to_lowercase();
+    let mut score = 0;
+
+    let os_tokens: &[&str] = match std::env::consts::OS {
+        "linux" => &["linux"],
+        "macos" => &["darwin", "macos", "osx", "apple"],
+        "windows" => &["windows", "win64", "win32"],
+        _ => &[],
+    };
+    if !os_tokens.iter().any(|t| lower.contains(t)) {
+        return 0;
+    }
+    score += 2;
+
+    let arch = std::env::consts::ARCH;
+    let arch_tokens: &[&str] = match arch {
+        "x86_64" => &["x86_64", "amd64", "x64"],
+        "aarch64" => &["aarch64", "arm64"],
+        _ => &[],
+    };
+    if arch_tokens.iter().any(|t| lower.contains(t)) || lower.contains(arch) {
+        score += 2;
+    }
+
+    const PREFERRED_EXTENSIONS: &[&str] = &[".tar.gz", ".tgz", ".zip", ".deb"];
+    if PREFERRED_EXTENSIONS.iter().any(|ext| lower.ends_with(ext)) {
+        score += 1;
+    }
+
+    score
+}
+
+/// Index of the asset whose name best matches this machine's OS/arch, if
+/// any asset scores above zero.
+pub fn best_match(assets: &[Asset]) -> Option<usize> {
+    assets
+        .iter()
+        .enumerate()
+        .map(|(idx, a)| (idx, score(&a.name)))
+        .filter(|(_, score)| *score > 0)
+        .max_by_key(|(_, score)| *score)
+        .map(|(idx, _)| idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::score;
+
+    #[test]
+    fn scores_zero_for_unrelated_os_token() {
+        // Whatever OS we're testing on, the name for this OS matches and
+        // the names naming only the other OSes score zero.
+        let linux_only = score("tool-linux-x86_64.tar.gz");
+        let darwin_only = score("tool-darwin-x86_64.tar.gz");
+        let windows_only = score("tool-windows-x86_64.zip");
+        assert!(linux_only > 0 || darwin_only > 0 || windows_only > 0);
+        let matching = [linux_only, darwin_only, windows_only]
+            .iter()
+            .filter(|s| **s > 0)
+            .count();
+        assert_eq!(matching, 1);
+    }
+
+    #[test]
+    fn darwin_asset_never_scores_as_windows() {
+        // "win" is a substring of "darwin", so a naive contains() check
+        // would wrongly treat a macOS asset as a Windows match.
+        if std::env::consts::OS == "windows" {
+            assert_eq!(score("tool-darwin-arm64.tar.gz"), 0);
+        }
+    }
+}
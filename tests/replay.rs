@@ -0,0 +1,106 @@
+//! Exercises the GitHub provider's paging math, rate-limit backoff, and
+//! release filtering against recorded fixtures instead of the network.
+
+use grit_find::cache::Cache;
+use grit_find::forge::{ForgeProvider, GitHubProvider};
+use grit_find::http::fixture_filename;
+use reqwest::Url;
+use std::{fs, sync::Mutex, time::Duration};
+
+// GRIT_FIND_REPLAY is process-global; serialize tests that touch it.
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+fn write_fixture(dir: &std::path::Path, url: &str, seq: u32, status: u16, body: &str) {
+    fs::create_dir_all(dir).unwrap();
+    let path = dir.join(fixture_filename("GET", url, seq));
+    let fixture = serde_json::json!({
+        "method": "GET",
+        "url": url,
+        "status": status,
+        "headers": {},
+        "body": body,
+    });
+    fs::write(path, serde_json::to_vec(&fixture).unwrap()).unwrap();
+}
+
+#[tokio::test]
+async fn replays_search_release_and_rate_limit_retry() {
+    let _guard = ENV_LOCK.lock().unwrap();
+    let dir = std::env::temp_dir().join(format!(
+        "grit-find-replay-test-{}",
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+
+    let host = "https://fixture.grit-find.test";
+
+    let search_url = Url::parse_with_params(
+        &format!("{host}/api/v3/search/repositories"),
+        [
+            ("q", "rust is:public"),
+            ("per_page", "2"),
+            ("page", "1"),
+            ("sort", "stars"),
+            ("order", "desc"),
+        ],
+    )
+    .unwrap();
+    write_fixture(
+        &dir,
+        search_url.as_str(),
+        0,
+        200,
+        r#"{"items":[
+            {"full_name":"octo/alpha","description":"demo repo","stargazers_count":10},
+            {"full_name":"octo/beta","description":null,"stargazers_count":5}
+        ]}"#,
+    );
+
+    // alpha: first probe hits the rate limit, second succeeds with a release.
+    let alpha_url = format!("{host}/api/v3/repos/octo/alpha/releases/latest");
+    write_fixture(&dir, &alpha_url, 0, 429, "");
+    write_fixture(
+        &dir,
+        &alpha_url,
+        1,
+        200,
+        r#"{"tag_name":"v1.0.0","name":"Release 1","assets":[
+            {"name":"alpha-linux-x86_64.tar.gz","browser_download_url":"https://dl.grit-find.test/alpha.tar.gz","size":123}
+        ]}"#,
+    );
+
+    // beta: no release available.
+    let beta_url = format!("{host}/api/v3/repos/octo/beta/releases/latest");
+    write_fixture(&dir, &beta_url, 0, 404, "");
+
+    std::env::remove_var("GRIT_FIND_RECORD");
+    std::env::set_var("GRIT_FIND_REPLAY", &dir);
+
+    let cache = Cache::new(Duration::from_secs(0), true);
+    let provider = GitHubProvider::new(Some(host), cache).expect("provider should build");
+
+    let repos = provider
+        .search_repos("rust", 2, 1)
+        .await
+        .expect("search should replay");
+    assert_eq!(repos.len(), 2, "paging math should keep both search results");
+    assert_eq!(repos[0].full_name, "octo/alpha");
+    assert_eq!(repos[1].stargazers_count, 5);
+
+    let alpha_release = provider
+        .latest_release("octo/alpha")
+        .await
+        .expect("should recover from the replayed 429 and return the release");
+    assert_eq!(alpha_release.tag_name, "v1.0.0");
+    assert_eq!(alpha_release.assets.len(), 1);
+    assert_eq!(alpha_release.assets[0].name, "alpha-linux-x86_64.tar.gz");
+
+    let beta_release = provider.latest_release("octo/beta").await;
+    assert!(
+        beta_release.is_err(),
+        "a repo with no release should surface as an error, as fetch_repos_page relies on"
+    );
+
+    std::env::remove_var("GRIT_FIND_REPLAY");
+    let _ = fs::remove_dir_all(&dir);
+}